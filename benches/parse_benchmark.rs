@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use json_parser::JsonParser;
+
+/// Builds a multi-megabyte JSON array of small objects so the benchmark
+/// exercises the parser's hot loop the same way a large real-world payload
+/// would.
+fn large_input(elements: usize) -> String {
+    let mut json = String::from("[");
+
+    for i in 0..elements {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(&format!(
+            r#"{{"id":{i},"name":"item-{i}","active":true,"score":{:.2}}}"#,
+            i as f64 / 3.0
+        ));
+    }
+
+    json.push(']');
+    json
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let input = large_input(50_000);
+
+    c.bench_function("parse large array", |b| {
+        b.iter(|| {
+            let mut parser = JsonParser::new(&input);
+            parser.parse().unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -4,9 +4,38 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("Unexcpected characters")]
-    UnexcpectedCharacters(),
+    #[error("{line}:{column}: Unexpected characters")]
+    UnexcpectedCharacters {
+        line: usize,
+        column: usize,
+        offset: usize,
+    },
 
-    #[error("Invalid input: {0}")]
-    InvalidInput(String),
+    #[error("{line}:{column}: {message}")]
+    InvalidInput {
+        message: String,
+        line: usize,
+        column: usize,
+        offset: usize,
+    },
+}
+
+impl Error {
+    /// The 1-based `(line, column)` and 0-based byte offset where this error
+    /// was detected.
+    pub fn position(&self) -> (usize, usize, usize) {
+        match self {
+            Error::UnexcpectedCharacters {
+                line,
+                column,
+                offset,
+            } => (*line, *column, *offset),
+            Error::InvalidInput {
+                line,
+                column,
+                offset,
+                ..
+            } => (*line, *column, *offset),
+        }
+    }
 }
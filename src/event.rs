@@ -0,0 +1,430 @@
+use std::io::Read;
+
+use crate::error::{Error, Result};
+use crate::parser::JsonValue;
+use crate::unicode::{self, ByteCursor};
+
+/// A single step of a streamed JSON document, emitted by [`EventReader`].
+///
+/// Objects and arrays are reported as matching `Start*`/`End*` pairs instead
+/// of being buffered whole, so a caller can react to (or bail out of) a
+/// document without ever holding more than one value in memory at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    StartObject,
+    Key(String),
+    StartArray,
+    Value(JsonValue),
+    EndArray,
+    EndObject,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Container {
+    Object,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Next {
+    Value,
+    ObjectKey { first: bool },
+    ObjectComma,
+    ArrayElement { first: bool },
+    ArrayComma,
+    RootEnd,
+}
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Pull-based, event-driven JSON parser that consumes from any `impl Read`.
+///
+/// Unlike [`JsonParser`](crate::JsonParser), which requires the full document
+/// as a `&str`, `EventReader` tracks only a stack of open containers plus a
+/// small read-ahead buffer, so it can walk documents larger than memory and
+/// stop early without reading the rest of the stream.
+pub struct EventReader<R: Read> {
+    reader: R,
+    buf: Vec<u8>,
+    pos: usize,
+    offset: usize,
+    line: usize,
+    column: usize,
+    stack: Vec<Container>,
+    expect: Next,
+    done: bool,
+}
+
+impl<R: Read> EventReader<R> {
+    pub fn new(reader: R) -> Self {
+        EventReader {
+            reader,
+            buf: Vec::new(),
+            pos: 0,
+            offset: 0,
+            line: 1,
+            column: 1,
+            stack: Vec::new(),
+            expect: Next::Value,
+            done: false,
+        }
+    }
+
+    fn advance(&mut self) -> Result<Option<Event>> {
+        loop {
+            match self.expect {
+                Next::Value => {
+                    self.skip_whitespace()?;
+                    return match self.peek()? {
+                        Some(b'{') => {
+                            self.bump();
+                            self.stack.push(Container::Object);
+                            self.expect = Next::ObjectKey { first: true };
+                            Ok(Some(Event::StartObject))
+                        }
+                        Some(b'[') => {
+                            self.bump();
+                            self.stack.push(Container::Array);
+                            self.expect = Next::ArrayElement { first: true };
+                            Ok(Some(Event::StartArray))
+                        }
+                        Some(_) if self.stack.is_empty() => {
+                            Err(self.err_invalid("Json should be an object or array"))
+                        }
+                        Some(b'"') => {
+                            let value = self.read_string()?;
+                            self.expect = self.after_value();
+                            Ok(Some(Event::Value(JsonValue::String(value))))
+                        }
+                        Some(b't') | Some(b'f') => {
+                            let value = self.read_bool()?;
+                            self.expect = self.after_value();
+                            Ok(Some(Event::Value(JsonValue::Boolean(value))))
+                        }
+                        Some(b'n') => {
+                            self.read_null()?;
+                            self.expect = self.after_value();
+                            Ok(Some(Event::Value(JsonValue::Null)))
+                        }
+                        Some(c) if c.is_ascii_digit() || c == b'-' => {
+                            let value = self.read_number()?;
+                            self.expect = self.after_value();
+                            Ok(Some(Event::Value(value)))
+                        }
+                        Some(_) => Err(self.err_unexpected()),
+                        None => Err(self.err_invalid("Unexpected end of input")),
+                    };
+                }
+                Next::ObjectKey { first } => {
+                    self.skip_whitespace()?;
+                    match self.peek()? {
+                        Some(b'}') if first => {
+                            self.bump();
+                            self.stack.pop();
+                            self.expect = self.after_value();
+                            return Ok(Some(Event::EndObject));
+                        }
+                        Some(b'"') => {
+                            let key = self.read_string()?;
+                            self.skip_whitespace()?;
+                            self.expect_byte(b':')?;
+                            self.bump();
+                            self.expect = Next::Value;
+                            return Ok(Some(Event::Key(key)));
+                        }
+                        _ => {
+                            return Err(self.err_invalid("Expected '\"' or '}'"))
+                        }
+                    }
+                }
+                Next::ObjectComma => {
+                    self.skip_whitespace()?;
+                    match self.peek()? {
+                        Some(b'}') => {
+                            self.bump();
+                            self.stack.pop();
+                            self.expect = self.after_value();
+                            return Ok(Some(Event::EndObject));
+                        }
+                        Some(b',') => {
+                            self.bump();
+                            self.expect = Next::ObjectKey { first: false };
+                        }
+                        _ => return Err(self.err_invalid("Expected ',' or '}'")),
+                    }
+                }
+                Next::ArrayElement { first } => {
+                    self.skip_whitespace()?;
+                    match self.peek()? {
+                        Some(b']') if first => {
+                            self.bump();
+                            self.stack.pop();
+                            self.expect = self.after_value();
+                            return Ok(Some(Event::EndArray));
+                        }
+                        _ => self.expect = Next::Value,
+                    }
+                }
+                Next::ArrayComma => {
+                    self.skip_whitespace()?;
+                    match self.peek()? {
+                        Some(b']') => {
+                            self.bump();
+                            self.stack.pop();
+                            self.expect = self.after_value();
+                            return Ok(Some(Event::EndArray));
+                        }
+                        Some(b',') => {
+                            self.bump();
+                            self.expect = Next::Value;
+                        }
+                        _ => return Err(self.err_invalid("Expected ',' or ']'")),
+                    }
+                }
+                Next::RootEnd => {
+                    self.skip_whitespace()?;
+                    return match self.peek()? {
+                        None => Ok(None),
+                        Some(_) => Err(self.err_unexpected()),
+                    };
+                }
+            }
+        }
+    }
+
+    fn after_value(&self) -> Next {
+        match self.stack.last() {
+            None => Next::RootEnd,
+            Some(Container::Object) => Next::ObjectComma,
+            Some(Container::Array) => Next::ArrayComma,
+        }
+    }
+
+    fn ensure(&mut self, n: usize) -> Result<bool> {
+        while self.buf.len() - self.pos < n {
+            if self.pos > 0 {
+                self.buf.drain(0..self.pos);
+                self.pos = 0;
+            }
+
+            let mut chunk = [0u8; CHUNK_SIZE];
+            let read = self
+                .reader
+                .read(&mut chunk)
+                .map_err(|e| self.err_invalid(e.to_string()))?;
+
+            if read == 0 {
+                return Ok(self.buf.len() - self.pos >= n);
+            }
+
+            self.buf.extend_from_slice(&chunk[..read]);
+        }
+
+        Ok(true)
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>> {
+        if self.ensure(1)? {
+            Ok(Some(self.buf[self.pos]))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn peek_at(&mut self, offset: usize) -> Result<Option<u8>> {
+        if self.ensure(offset + 1)? {
+            Ok(Some(self.buf[self.pos + offset]))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn bump(&mut self) {
+        if self.buf.get(self.pos) == Some(&b'\n') {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        self.pos += 1;
+        self.offset += 1;
+    }
+
+    fn err_invalid(&self, message: impl Into<String>) -> Error {
+        Error::InvalidInput {
+            message: message.into(),
+            line: self.line,
+            column: self.column,
+            offset: self.offset,
+        }
+    }
+
+    fn err_unexpected(&self) -> Error {
+        Error::UnexcpectedCharacters {
+            line: self.line,
+            column: self.column,
+            offset: self.offset,
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> Result<()> {
+        while let Some(b) = self.peek()? {
+            if matches!(b, b' ' | b'\t' | b'\n' | b'\r') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn expect_byte(&mut self, expected: u8) -> Result<()> {
+        match self.peek()? {
+            Some(b) if b == expected => Ok(()),
+            _ => Err(self.err_invalid(format!("Expected '{}'", expected as char))),
+        }
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        self.expect_byte(b'"')?;
+        self.bump();
+
+        let mut out = String::new();
+        loop {
+            match self.peek()? {
+                None => return Err(self.err_invalid("Unterminated string")),
+                Some(b'"') => {
+                    self.bump();
+                    return Ok(out);
+                }
+                Some(b'\\') => {
+                    self.bump();
+                    out.push(unicode::decode_escape(self)?);
+                }
+                Some(b'\t') => return Err(self.err_invalid("Tab character in string")),
+                Some(b'\n') => return Err(self.err_invalid("Line break in string")),
+                Some(_) => out.push(unicode::read_char(self)?),
+            }
+        }
+    }
+
+    fn read_number(&mut self) -> Result<JsonValue> {
+        let mut token = String::new();
+        let mut is_float = false;
+
+        while let Some(b) = self.peek()? {
+            let c = b as char;
+            if matches!(c, '.' | 'e' | 'E') {
+                is_float = true;
+                token.push(c);
+                self.bump();
+            } else if c.is_ascii_digit() || matches!(c, '-' | '+') {
+                token.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        if is_float {
+            return token
+                .parse::<f64>()
+                .map(JsonValue::Float)
+                .map_err(|_| self.err_invalid("Invalid number"));
+        }
+
+        if token.as_bytes().first() == Some(&b'0') && token.len() > 1 {
+            return Err(self.err_invalid("Cant start with 0"));
+        }
+
+        if let Ok(n) = token.parse::<i64>() {
+            Ok(JsonValue::Integer(n))
+        } else if let Ok(n) = token.parse::<u64>() {
+            Ok(JsonValue::UInteger(n))
+        } else {
+            token
+                .parse::<f64>()
+                .map(JsonValue::Float)
+                .map_err(|_| self.err_invalid("Invalid number"))
+        }
+    }
+
+    fn read_bool(&mut self) -> Result<bool> {
+        if self.consume_literal("true")? {
+            Ok(true)
+        } else if self.consume_literal("false")? {
+            Ok(false)
+        } else {
+            Err(self.err_invalid("Invalid boolean value"))
+        }
+    }
+
+    fn read_null(&mut self) -> Result<()> {
+        if self.consume_literal("null")? {
+            Ok(())
+        } else {
+            Err(self.err_invalid("Invalid null value"))
+        }
+    }
+
+    fn consume_literal(&mut self, literal: &str) -> Result<bool> {
+        for (i, expected) in literal.bytes().enumerate() {
+            match self.peek_at(i)? {
+                Some(b) if b == expected => {}
+                _ => return Ok(false),
+            }
+        }
+
+        for _ in 0..literal.len() {
+            self.bump();
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> ByteCursor for EventReader<R> {
+    fn peek_byte(&mut self) -> Result<Option<u8>> {
+        self.peek()
+    }
+
+    fn peek_byte_at(&mut self, offset: usize) -> Result<Option<u8>> {
+        self.peek_at(offset)
+    }
+
+    fn advance_byte(&mut self) {
+        self.bump();
+    }
+
+    fn advance_char(&mut self, len: usize) {
+        self.pos += len;
+        self.offset += len;
+        self.column += 1;
+    }
+
+    fn fail(&self, message: impl Into<String>) -> Error {
+        self.err_invalid(message)
+    }
+}
+
+impl<R: Read> Iterator for EventReader<R> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.advance() {
+            Ok(Some(event)) => Some(Ok(event)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod error;
+mod event;
+mod parser;
+mod unicode;
+mod writer;
+
+pub use error::{Error, Result};
+pub use event::{Event, EventReader};
+pub use parser::{JsonParser, JsonValue};
@@ -1,25 +1,32 @@
 use crate::error::{Error, Result};
+use crate::unicode::{self, ByteCursor};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum JsonValue {
     Object(Vec<(String, JsonValue)>),
     Array(Vec<JsonValue>),
     String(String),
-    Number(f64),
+    Integer(i64),
+    UInteger(u64),
+    Float(f64),
     Boolean(bool),
     Null,
 }
 
 pub struct JsonParser<'a> {
-    json_string: &'a str,
+    data: &'a [u8],
     index: usize,
+    line: usize,
+    column: usize,
 }
 
 impl<'a> JsonParser<'a> {
     pub fn new(json_string: &'a str) -> Self {
         JsonParser {
-            json_string,
+            data: json_string.as_bytes(),
             index: 0,
+            line: 1,
+            column: 1,
         }
     }
 
@@ -31,8 +38,8 @@ impl<'a> JsonParser<'a> {
         let result = self.parse_value()?;
         self.consume_whitespace();
 
-        if self.index != self.json_string.len() {
-            return Err(Error::UnexcpectedCharacters());
+        if self.index != self.data.len() {
+            return Err(self.err_unexpected());
         }
 
         Ok(result)
@@ -41,228 +48,293 @@ impl<'a> JsonParser<'a> {
     fn parse_value(&mut self) -> Result<JsonValue> {
         self.consume_whitespace();
 
-        let next_char = self.json_string.chars().nth(self.index);
-
-        match next_char {
-            Some('{') => self.parse_object(),
-            Some('[') => self.parse_array(),
-            Some('"') => self.parse_string(),
-            Some(c) if c.is_ascii_digit() || c == '-' => self.parse_number(),
-            Some('t') | Some('f') => self.parse_boolean(),
-            Some('n') => self.parse_null(),
-            _ => Err(Error::UnexcpectedCharacters()),
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => self.parse_string(),
+            Some(b) if b.is_ascii_digit() || b == b'-' => self.parse_number(),
+            Some(b't') | Some(b'f') => self.parse_boolean(),
+            Some(b'n') => self.parse_null(),
+            _ => Err(self.err_unexpected()),
         }
     }
 
     fn parse_object(&mut self) -> Result<JsonValue> {
-        self.consume('{');
+        self.consume(b'{');
         let mut result = Vec::new();
 
-        while self.json_string.chars().nth(self.index) != Some('}') {
+        while self.peek() != Some(b'}') {
             self.consume_whitespace();
 
             let key = match self.parse_string()? {
                 JsonValue::String(val) => val,
-                _ => return Err(Error::UnexcpectedCharacters()),
+                _ => return Err(self.err_unexpected()),
             };
 
             // Cases when key is empty
             if key.is_empty() {
-                self.consume('"');
+                self.consume(b'"');
             }
 
             self.consume_whitespace();
 
-            self.expect(':')?;
-            self.consume(':');
+            self.expect(b':')?;
+            self.consume(b':');
             self.consume_whitespace();
 
             let value = self.parse_value()?;
 
             result.push((key, value));
-            self.consume('"');
+            self.consume(b'"');
 
             self.consume_whitespace();
 
-            if self.json_string.chars().nth(self.index) == Some(',') {
-                self.consume(',');
-                self.consume('\n');
-            } else if self.json_string.chars().nth(self.index) != Some('}') {
-                return Err(Error::InvalidInput("Expected '}' or ','".to_string()));
+            if self.peek() == Some(b',') {
+                self.consume(b',');
+                self.consume(b'\n');
+            } else if self.peek() != Some(b'}') {
+                return Err(self.err_invalid("Expected '}' or ','"));
             }
 
             self.consume_whitespace();
         }
 
-        self.consume('}');
+        self.consume(b'}');
 
         Ok(JsonValue::Object(result))
     }
 
     fn parse_array(&mut self) -> Result<JsonValue> {
-        self.consume('[');
+        self.consume(b'[');
         let mut result = Vec::new();
 
-        while self.json_string.chars().nth(self.index) != Some(']') {
+        while self.peek() != Some(b']') {
             let value = self.parse_value()?;
             result.push(value);
 
             self.consume_whitespace();
 
-            if self.json_string.chars().nth(self.index) == Some(',') {
-                self.consume(',');
-            } else if self.json_string.chars().nth(self.index) != Some(']') {
-                return Err(Error::InvalidInput("Expected ']' or ','".to_string()));
+            if self.peek() == Some(b',') {
+                self.consume(b',');
+            } else if self.peek() != Some(b']') {
+                return Err(self.err_invalid("Expected ']' or ','"));
             }
         }
 
-        self.consume(']'); // Consume ']'
+        self.consume(b']'); // Consume ']'
         Ok(JsonValue::Array(result))
     }
 
     fn parse_boolean(&mut self) -> Result<JsonValue> {
         self.consume_whitespace();
 
-        let next_char = self.json_string.chars().nth(self.index);
-
-        if next_char == Some('t') {
-            self.index += 4;
-            Ok(JsonValue::Boolean(true))
-        } else if next_char == Some('f') {
-            self.index += 5;
-            Ok(JsonValue::Boolean(false))
-        } else {
-            Err(Error::InvalidInput("Invalid boolean value".to_string()))
+        match self.peek() {
+            Some(b't') => {
+                self.bump_by(4);
+                Ok(JsonValue::Boolean(true))
+            }
+            Some(b'f') => {
+                self.bump_by(5);
+                Ok(JsonValue::Boolean(false))
+            }
+            _ => Err(self.err_invalid("Invalid boolean value")),
         }
     }
 
     fn parse_number(&mut self) -> Result<JsonValue> {
         let start = self.index;
-        let mut has_dot = false;
+        let mut is_float = false;
 
-        while let Some(c) = self.json_string.chars().nth(self.index) {
-            if c == '.' || c == 'e' {
-                has_dot = true;
-            } else if !c.is_ascii_digit() && c != 'e' && c != 'E' && c != '-' && c != '+' {
+        while let Some(b) = self.peek() {
+            if matches!(b, b'.' | b'e' | b'E') {
+                is_float = true;
+            } else if !b.is_ascii_digit() && b != b'-' && b != b'+' {
                 break;
             }
 
-            self.index += 1;
+            self.bump();
         }
 
-        let number_str = &self.json_string[start..self.index];
+        // All bytes scanned above are ASCII, so this slice is valid UTF-8.
+        let number_str = std::str::from_utf8(&self.data[start..self.index]).unwrap();
 
-        if has_dot {
+        if is_float {
             match number_str.parse::<f64>() {
-                Ok(num) => Ok(JsonValue::Number(num)),
-                Err(_) => Err(Error::InvalidInput("Invalid number".to_string())),
+                Ok(num) => Ok(JsonValue::Float(num)),
+                Err(_) => Err(self.err_invalid("Invalid number")),
             }
         } else {
-            if self.json_string.chars().nth(start) == Some('0') && number_str.len() > 1 {
-                return Err(Error::InvalidInput("Cant start with 0".to_string()));
+            if self.data.get(start) == Some(&b'0') && number_str.len() > 1 {
+                return Err(self.err_invalid("Cant start with 0"));
             }
 
-            match number_str.parse::<i64>() {
-                Ok(num) => Ok(JsonValue::Number(num as f64)),
-                Err(_) => Err(Error::InvalidInput("Invalid number".to_string())),
+            // Widen from i64 to u64 to f64 so large unsigned IDs and
+            // out-of-range values still parse instead of erroring out.
+            if let Ok(num) = number_str.parse::<i64>() {
+                Ok(JsonValue::Integer(num))
+            } else if let Ok(num) = number_str.parse::<u64>() {
+                Ok(JsonValue::UInteger(num))
+            } else {
+                match number_str.parse::<f64>() {
+                    Ok(num) => Ok(JsonValue::Float(num)),
+                    Err(_) => Err(self.err_invalid("Invalid number")),
+                }
             }
         }
     }
 
     fn parse_string(&mut self) -> Result<JsonValue> {
-        self.consume('"');
+        self.consume(b'"');
         self.consume_whitespace();
-        let start = self.index;
 
-        while let Some(c) = self.json_string.chars().nth(self.index) {
-            self.index += 1;
+        let mut result = String::new();
 
-            if c == '\\' {
-                let next_charachter = self.json_string.chars().nth(self.index);
-                if !Self::is_valid_escape(next_charachter.unwrap()) {
-                    return Err(Error::InvalidInput("Invalid escape_char".to_string()));
-                }
-                self.index += 1;
+        while let Some(b) = self.peek() {
+            if b >= 0x80 {
+                result.push(unicode::read_char(self)?);
+                continue;
+            }
 
+            self.bump();
+            let c = b as char;
+
+            if c == '\\' {
+                result.push(unicode::decode_escape(self)?);
                 continue;
             }
 
             if c == '\t' {
-                return Err(Error::InvalidInput("Tab character in string".to_string()));
+                return Err(self.err_invalid("Tab character in string"));
             }
 
             if c == '\n' {
-                return Err(Error::InvalidInput("Line break in string".to_string()));
+                return Err(self.err_invalid("Line break in string"));
             }
 
             if c == '"' {
-                return Ok(JsonValue::String(
-                    self.json_string[start..self.index - 1].to_string(),
-                ));
+                return Ok(JsonValue::String(result));
             }
+
+            result.push(c);
         }
 
-        Err(Error::InvalidInput("Unterminated string".to_string()))
+        Err(self.err_invalid("Unterminated string"))
     }
 
-    fn is_valid_escape(c: char) -> bool {
-        matches!(c, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't' | 'u')
+    fn parse_null(&mut self) -> Result<JsonValue> {
+        if self.peek() == Some(b'n') {
+            self.bump_by(4);
+            Ok(JsonValue::Null)
+        } else {
+            Err(self.err_invalid("Invalid null value"))
+        }
     }
 
-    fn parse_null(&mut self) -> Result<JsonValue> {
-        let next_char = self.json_string.chars().nth(self.index);
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.index).copied()
+    }
 
-        if next_char == Some('n') {
-            self.index += 4;
-            Ok(JsonValue::Null)
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.data.get(self.index + offset).copied()
+    }
+
+    /// Advances the cursor by a single byte, tracking line/column position.
+    fn bump(&mut self) {
+        if self.data.get(self.index) == Some(&b'\n') {
+            self.line += 1;
+            self.column = 1;
         } else {
-            Err(Error::InvalidInput("Invalid null value".to_string()))
+            self.column += 1;
         }
+        self.index += 1;
+    }
+
+    /// Advances the cursor by `n` bytes that are known to contain no
+    /// newlines (the `true`, `false` and `null` literal keywords).
+    fn bump_by(&mut self, n: usize) {
+        self.index += n;
+        self.column += n;
     }
 
-    fn consume(&mut self, ch: char) {
-        if Some(ch) == self.json_string.chars().nth(self.index) {
-            self.index += 1;
+    fn consume(&mut self, byte: u8) {
+        if self.peek() == Some(byte) {
+            self.bump();
         }
     }
 
     fn consume_whitespace(&mut self) {
-        while let Some(c) = self.json_string.chars().nth(self.index) {
-            if !c.is_whitespace() {
+        while let Some(b) = self.peek() {
+            if !matches!(b, b' ' | b'\t' | b'\n' | b'\r') {
                 break;
             }
-            self.index += 1;
+            self.bump();
         }
     }
 
-    fn expect(&mut self, expected: char) -> Result<()> {
-        if self.json_string.chars().nth(self.index) == Some(expected) {
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        if self.peek() == Some(expected) {
             Ok(())
         } else {
-            Err(Error::InvalidInput(format!(
-                "Expected: {expected} and not found"
-            )))
+            Err(self.err_invalid(format!("Expected: {} and not found", expected as char)))
         }
     }
 
     fn sanity_check(&self) -> Result<()> {
-        let first_char = match self.json_string.chars().next() {
-            Some(c) => c,
-            None => return Err(Error::InvalidInput("Invalid input".to_string())),
+        let first_byte = match self.data.first() {
+            Some(&b) => b,
+            None => return Err(self.err_invalid("Invalid input")),
         };
 
-        match first_char {
-            '{' | '[' => {
+        match first_byte {
+            b'{' | b'[' => {
                 // Check for trailing commas
-                if self.json_string.ends_with(",}") || self.json_string.ends_with(",]") {
-                    return Err(Error::InvalidInput("Trailing comma detected".to_string()));
+                if self.data.ends_with(b",}") || self.data.ends_with(b",]") {
+                    return Err(self.err_invalid("Trailing comma detected"));
                 }
 
                 Ok(())
             }
-            _ => Err(Error::InvalidInput(
-                "Json should be an object or array".to_string(),
-            )),
+            _ => Err(self.err_invalid("Json should be an object or array")),
         }
     }
+
+    fn err_invalid(&self, message: impl Into<String>) -> Error {
+        Error::InvalidInput {
+            message: message.into(),
+            line: self.line,
+            column: self.column,
+            offset: self.index,
+        }
+    }
+
+    fn err_unexpected(&self) -> Error {
+        Error::UnexcpectedCharacters {
+            line: self.line,
+            column: self.column,
+            offset: self.index,
+        }
+    }
+}
+
+impl ByteCursor for JsonParser<'_> {
+    fn peek_byte(&mut self) -> Result<Option<u8>> {
+        Ok(JsonParser::peek(self))
+    }
+
+    fn peek_byte_at(&mut self, offset: usize) -> Result<Option<u8>> {
+        Ok(JsonParser::peek_at(self, offset))
+    }
+
+    fn advance_byte(&mut self) {
+        self.bump();
+    }
+
+    fn advance_char(&mut self, len: usize) {
+        self.index += len;
+        self.column += 1;
+    }
+
+    fn fail(&self, message: impl Into<String>) -> Error {
+        self.err_invalid(message)
+    }
 }
@@ -0,0 +1,129 @@
+use crate::error::{Error, Result};
+
+/// Byte-level cursor operations needed to decode UTF-8 characters and
+/// string escapes, shared by [`JsonParser`](crate::JsonParser) (in-memory)
+/// and [`EventReader`](crate::EventReader) (streamed) so the decoding logic
+/// itself only has to live in one place.
+pub(crate) trait ByteCursor {
+    fn peek_byte(&mut self) -> Result<Option<u8>>;
+    fn peek_byte_at(&mut self, offset: usize) -> Result<Option<u8>>;
+
+    /// Advances past a single ASCII byte, tracking line/column.
+    fn advance_byte(&mut self);
+
+    /// Advances past a `len`-byte UTF-8 sequence that decodes to a single
+    /// `char`, advancing the column by exactly 1 (never by `len`) so column
+    /// numbers count decoded characters, not raw bytes.
+    fn advance_char(&mut self, len: usize);
+
+    fn fail(&self, message: impl Into<String>) -> Error;
+}
+
+pub(crate) fn utf8_len(lead: u8) -> usize {
+    if lead & 0x80 == 0 {
+        1
+    } else if lead & 0xE0 == 0xC0 {
+        2
+    } else if lead & 0xF0 == 0xE0 {
+        3
+    } else {
+        4
+    }
+}
+
+pub(crate) fn is_valid_escape(c: char) -> bool {
+    matches!(c, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't' | 'u')
+}
+
+pub(crate) fn read_char<C: ByteCursor>(cursor: &mut C) -> Result<char> {
+    let lead = cursor
+        .peek_byte()?
+        .ok_or_else(|| cursor.fail("Unterminated string"))?;
+    let len = utf8_len(lead);
+
+    let mut bytes = [0u8; 4];
+    for (i, slot) in bytes.iter_mut().enumerate().take(len) {
+        *slot = cursor
+            .peek_byte_at(i)?
+            .ok_or_else(|| cursor.fail("Invalid UTF-8 sequence"))?;
+    }
+
+    let decoded =
+        std::str::from_utf8(&bytes[..len]).map_err(|_| cursor.fail("Invalid UTF-8 sequence"))?;
+    let c = decoded.chars().next().unwrap();
+
+    cursor.advance_char(len);
+    Ok(c)
+}
+
+/// Decodes the character(s) following a `\` in a string literal. The
+/// backslash itself must already have been consumed.
+pub(crate) fn decode_escape<C: ByteCursor>(cursor: &mut C) -> Result<char> {
+    let next = cursor
+        .peek_byte()?
+        .ok_or_else(|| cursor.fail("Unterminated string"))?;
+
+    if !is_valid_escape(next as char) {
+        return Err(cursor.fail("Invalid escape_char"));
+    }
+    cursor.advance_byte();
+
+    match next {
+        b'"' => Ok('"'),
+        b'\\' => Ok('\\'),
+        b'/' => Ok('/'),
+        b'b' => Ok('\u{08}'),
+        b'f' => Ok('\u{0c}'),
+        b'n' => Ok('\n'),
+        b'r' => Ok('\r'),
+        b't' => Ok('\t'),
+        b'u' => decode_unicode_escape(cursor),
+        _ => unreachable!(),
+    }
+}
+
+/// Decodes a `\uXXXX` escape, combining a high/low UTF-16 surrogate pair
+/// into a single `char` when one is present.
+pub(crate) fn decode_unicode_escape<C: ByteCursor>(cursor: &mut C) -> Result<char> {
+    let high = read_hex4(cursor)?;
+
+    if (0xD800..=0xDBFF).contains(&high) {
+        if cursor.peek_byte()? != Some(b'\\') || cursor.peek_byte_at(1)? != Some(b'u') {
+            return Err(cursor.fail("Unpaired UTF-16 surrogate"));
+        }
+        cursor.advance_byte();
+        cursor.advance_byte();
+
+        let low = read_hex4(cursor)?;
+        if !(0xDC00..=0xDFFF).contains(&low) {
+            return Err(cursor.fail("Invalid low surrogate"));
+        }
+
+        let code_point = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+        return char::from_u32(code_point).ok_or_else(|| cursor.fail("Invalid surrogate pair"));
+    }
+
+    if (0xDC00..=0xDFFF).contains(&high) {
+        return Err(cursor.fail("Unpaired UTF-16 surrogate"));
+    }
+
+    char::from_u32(high).ok_or_else(|| cursor.fail("Invalid \\u escape"))
+}
+
+pub(crate) fn read_hex4<C: ByteCursor>(cursor: &mut C) -> Result<u32> {
+    let mut value = 0u32;
+
+    for _ in 0..4 {
+        let b = cursor
+            .peek_byte()?
+            .ok_or_else(|| cursor.fail("Unterminated \\u escape"))?;
+        let digit = (b as char)
+            .to_digit(16)
+            .ok_or_else(|| cursor.fail("Invalid hex digit in \\u escape"))?;
+
+        value = (value << 4) | digit;
+        cursor.advance_byte();
+    }
+
+    Ok(value)
+}
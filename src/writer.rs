@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::parser::JsonValue;
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Object(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "\"{}\":{}", escape(key), value)?;
+                }
+                write!(f, "}}")
+            }
+            JsonValue::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::String(s) => write!(f, "\"{}\"", escape(s)),
+            JsonValue::Integer(n) => write!(f, "{n}"),
+            JsonValue::UInteger(n) => write!(f, "{n}"),
+            JsonValue::Float(n) => write!(f, "{}", format_float(*n)),
+            JsonValue::Boolean(b) => write!(f, "{b}"),
+            JsonValue::Null => write!(f, "null"),
+        }
+    }
+}
+
+impl JsonValue {
+    /// Serializes this value into a multi-line JSON string, indenting nested
+    /// objects and arrays by `indent` spaces per level.
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, depth: usize) {
+        match self {
+            JsonValue::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            JsonValue::Object(entries) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    push_indent(out, indent, depth + 1);
+                    out.push('"');
+                    out.push_str(&escape(key));
+                    out.push_str("\": ");
+                    value.write_pretty(out, indent, depth + 1);
+                    if i + 1 < entries.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                push_indent(out, indent, depth);
+                out.push('}');
+            }
+            JsonValue::Array(items) if items.is_empty() => out.push_str("[]"),
+            JsonValue::Array(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    push_indent(out, indent, depth + 1);
+                    item.write_pretty(out, indent, depth + 1);
+                    if i + 1 < items.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                push_indent(out, indent, depth);
+                out.push(']');
+            }
+            _ => out.push_str(&self.to_string()),
+        }
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize, depth: usize) {
+    out.extend(std::iter::repeat_n(' ', indent * depth));
+}
+
+fn format_float(n: f64) -> String {
+    let s = format!("{n}");
+    if s.contains('.') || s.contains('e') || s.contains('E') || !n.is_finite() {
+        s
+    } else {
+        format!("{s}.0")
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
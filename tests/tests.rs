@@ -19,7 +19,7 @@ macro_rules! assert_parser {
 
 #[cfg(test)]
 mod tests {
-    use json_parser::JsonParser;
+    use json_parser::{JsonParser, JsonValue};
     use std::fs;
 
     #[test]
@@ -62,4 +62,77 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn serialize_round_trips_through_parse() {
+        let json_str = r#"{"name":"Jo\"hn\n","nums":[1,2.5],"ok":true,"none":null}"#;
+
+        let original = JsonParser::new(json_str).parse().unwrap();
+
+        let compact = original.to_string();
+        assert_eq!(JsonParser::new(&compact).parse().unwrap(), original);
+
+        let pretty = original.to_string_pretty(2);
+        assert_eq!(JsonParser::new(&pretty).parse().unwrap(), original);
+    }
+
+    #[test]
+    fn event_reader_streams_over_multiple_chunks_and_stops_early() {
+        use json_parser::{Event, EventReader};
+        use std::io::Cursor;
+
+        let padding = "x".repeat(9000); // forces more than one internal buffer fill
+        let json = format!(r#"{{"data": "{padding}", "items": [1, 2, 3]}}"#);
+
+        let mut reader = EventReader::new(Cursor::new(json.into_bytes()));
+
+        assert_eq!(reader.next().unwrap().unwrap(), Event::StartObject);
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            Event::Key("data".to_string())
+        );
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            Event::Value(JsonValue::String(padding))
+        );
+
+        // Dropping the reader here (without exhausting the rest of the
+        // stream) must not panic or read the remaining bytes eagerly.
+    }
+
+    #[test]
+    fn number_widening_picks_the_narrowest_matching_type() {
+        let json = format!("[{}, {}, {}, 1.5]", i64::MAX, i64::MIN, u64::MAX);
+
+        match JsonParser::new(&json).parse().unwrap() {
+            JsonValue::Array(items) => {
+                assert_eq!(items[0], JsonValue::Integer(i64::MAX));
+                assert_eq!(items[1], JsonValue::Integer(i64::MIN));
+                assert_eq!(items[2], JsonValue::UInteger(u64::MAX));
+                assert_eq!(items[3], JsonValue::Float(1.5));
+            }
+            other => panic!("expected array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unicode_escape_decodes_surrogate_pairs_and_rejects_lone_surrogates() {
+        let value = JsonParser::new("[\"\\uD83D\\uDE00\"]").parse().unwrap();
+        assert_eq!(
+            value,
+            JsonValue::Array(vec![JsonValue::String("\u{1F600}".to_string())])
+        );
+
+        assert!(JsonParser::new(r#"["\uD83D"]"#).parse().is_err());
+        assert!(JsonParser::new(r#"["\uDE00"]"#).parse().is_err());
+    }
+
+    #[test]
+    fn parse_error_reports_line_and_column_of_malformed_token() {
+        let json = "{\n  \"a\": 1,\n  \"b\": \n}";
+
+        let err = JsonParser::new(json).parse().unwrap_err();
+
+        assert_eq!(err.position(), (4, 1, json.len() - 1));
+    }
 }